@@ -0,0 +1,35 @@
+package executor
+
+import "strings"
+
+// executorAliases maps normalized executor spellings to built-in executor types.
+// Keys are lower case with spaces and hyphens folded to underscores.
+var executorAliases = map[string]ExecutorType{
+	"claude":         ExecutorClaudeCode,
+	"claude_code":    ExecutorClaudeCode,
+	"claudecode":     ExecutorClaudeCode,
+	"codex":          ExecutorCodex,
+	"openai_codex":   ExecutorCodex,
+	"droid":          ExecutorDroid,
+	"factory_droid":  ExecutorDroid,
+	"gemini":         ExecutorGemini,
+	"gemini_cli":     ExecutorGemini,
+	"qwen":           ExecutorQwen,
+	"qwen_code":      ExecutorQwen,
+	"copilot":        ExecutorCopilot,
+	"github_copilot": ExecutorCopilot,
+}
+
+// ParseExecutorType resolves a human friendly executor name such as
+// "Claude Code" or "claude-code" to its built-in ExecutorType.
+func ParseExecutorType(name string) (ExecutorType, bool) {
+	executorType, ok := executorAliases[normalizeExecutorName(name)]
+	return executorType, ok
+}
+
+func normalizeExecutorName(name string) string {
+	parts := strings.FieldsFunc(strings.ToLower(name), func(r rune) bool {
+		return r == ' ' || r == '-' || r == '_'
+	})
+	return strings.Join(parts, "_")
+}
@@ -0,0 +1,34 @@
+package executor
+
+import "testing"
+
+func TestParseExecutorType(t *testing.T) {
+	cases := []struct {
+		input string
+		want  ExecutorType
+	}{
+		{input: "claude_code", want: ExecutorClaudeCode},
+		{input: "Claude Code", want: ExecutorClaudeCode},
+		{input: "claude-code", want: ExecutorClaudeCode},
+		{input: " CLAUDE ", want: ExecutorClaudeCode},
+		{input: "Codex", want: ExecutorCodex},
+		{input: "GitHub Copilot", want: ExecutorCopilot},
+		{input: "gemini-cli", want: ExecutorGemini},
+		{input: "Qwen Code", want: ExecutorQwen},
+		{input: "factory-droid", want: ExecutorDroid},
+	}
+
+	for _, tc := range cases {
+		got, ok := ParseExecutorType(tc.input)
+		if !ok || got != tc.want {
+			t.Errorf("ParseExecutorType(%q) = %q, %v; want %q", tc.input, got, ok, tc.want)
+		}
+	}
+
+	if _, ok := ParseExecutorType("unknown"); ok {
+		t.Error("expected unknown executor name to be rejected")
+	}
+	if _, ok := ParseExecutorType(""); ok {
+		t.Error("expected empty executor name to be rejected")
+	}
+}
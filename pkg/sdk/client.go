@@ -127,9 +127,7 @@ func (c *Client) Execute(ctx context.Context, req executor.ExecuteRequest) (exec
 	if req.Prompt == "" {
 		return executor.ExecuteResponse{}, ErrPromptRequired
 	}
-	if req.Executor == "" {
-		req.Executor = executor.ExecutorClaudeCode
-	}
+	req.Executor = c.resolveExecutorType(req.Executor)
 
 	sessionID := uuid.New().String()
 	opts := executor.Options{
@@ -145,6 +143,11 @@ func (c *Client) Execute(ctx context.Context, req executor.ExecuteRequest) (exec
 
 	exec, err := c.registry.CreateSession(sessionID, string(req.Executor), opts)
 	if err != nil {
+		if errors.Is(err, executor.ErrUnknownExecutorType) {
+			names := c.registry.Executors()
+			sort.Strings(names)
+			err = fmt.Errorf("%w %q, accepted: %s", err, req.Executor, strings.Join(names, ", "))
+		}
 		return executor.ExecuteResponse{}, err
 	}
 
@@ -174,6 +177,23 @@ func (c *Client) Execute(ctx context.Context, req executor.ExecuteRequest) (exec
 	return executor.ExecuteResponse{SessionID: sessionID, Status: "running"}, nil
 }
 
+// resolveExecutorType maps friendly executor names like "Claude Code" to a built-in type.
+// Names that are registered as-is are kept, so custom executors are never shadowed.
+func (c *Client) resolveExecutorType(name executor.ExecutorType) executor.ExecutorType {
+	if name == "" {
+		return executor.ExecutorClaudeCode
+	}
+	for _, registered := range c.registry.Executors() {
+		if registered == string(name) {
+			return name
+		}
+	}
+	if executorType, ok := executor.ParseExecutorType(string(name)); ok {
+		return executorType
+	}
+	return name
+}
+
 func (c *Client) pipeSessionLogs(sessionID, executorName string, exec executor.Executor) {
 	done := false
 	defer func() {
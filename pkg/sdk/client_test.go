@@ -3,7 +3,9 @@ package sdk
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
+	"strings"
 	"sync/atomic"
 	"testing"
 	"time"
@@ -137,6 +139,32 @@ func TestExecuteValidation(t *testing.T) {
 	}
 }
 
+func TestExecuteResolvesExecutorAliases(t *testing.T) {
+	registry := executor.NewRegistry()
+	client := NewWithOptions(ClientOptions{Registry: registry, StreamManager: streaming.NewManager(), EventStore: store.NewMemoryEventStore()})
+
+	registry.Register(string(executor.ExecutorClaudeCode), executor.FactoryFunc(func() (executor.Executor, error) {
+		return &testExecutor{logs: make(chan executor.Log, 10), done: make(chan struct{})}, nil
+	}))
+
+	resp, err := client.Execute(context.Background(), executor.ExecuteRequest{Prompt: "hello", Executor: "Claude Code"})
+	if err != nil {
+		t.Fatalf("execute with executor alias failed: %v", err)
+	}
+	req, _, ok := client.getSessionRuntime(resp.SessionID)
+	if !ok || req.Executor != executor.ExecutorClaudeCode {
+		t.Fatalf("expected executor to resolve to %s, got %+v", executor.ExecutorClaudeCode, req)
+	}
+
+	_, err = client.Execute(context.Background(), executor.ExecuteRequest{Prompt: "hello", Executor: "nope"})
+	if !errors.Is(err, executor.ErrUnknownExecutorType) {
+		t.Fatalf("expected ErrUnknownExecutorType, got %v", err)
+	}
+	if !strings.Contains(err.Error(), string(executor.ExecutorClaudeCode)) {
+		t.Fatalf("expected error to list accepted executors, got %q", err.Error())
+	}
+}
+
 func TestListSessions(t *testing.T) {
 	registry := executor.NewRegistry()
 	streamMgr := streaming.NewManager()